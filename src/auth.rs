@@ -0,0 +1,230 @@
+/// Authentication module: JWT issuance and the extractor that enforces it
+///
+/// A `Config` loaded once at startup from environment variables, a login handler
+/// that verifies credentials against the `users` table and mints a token, and an
+/// Actix `FromRequest` extractor that validates the `Authorization: Bearer` header
+/// on every route that takes it as a parameter.
+
+use std::future::{ready, Ready};
+
+use actix_web::{dev::Payload, web, Error as ActixError, FromRequest, HttpRequest, HttpResponse};
+use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+use tiberius::Query;
+use utoipa::ToSchema;
+
+use crate::db::DbClient;
+use crate::models::Claims;
+
+/// JWT settings loaded once at startup and shared via `web::Data`
+#[derive(Clone)]
+pub struct Config {
+    /// Symmetric key used to sign and verify tokens
+    pub jwt_secret: String,
+
+    /// Token lifetime in seconds, used to compute the `exp` claim
+    pub jwt_expires_in: i64,
+
+    /// Max age in minutes; the issued token's `exp` is computed from
+    /// `jwt_expires_in`, not from this value
+    pub jwt_maxage: i64,
+}
+
+impl Config {
+    /// Load JWT settings from the environment
+    ///
+    /// # Environment Variables Required
+    /// - `JWT_SECRET`: symmetric key used to sign and verify tokens
+    ///
+    /// # Environment Variables Optional
+    /// - `JWT_EXPIRES_IN_SECONDS`: token lifetime in seconds (default `900`)
+    /// - `JWT_MAXAGE_MINUTES`: max age in minutes, stored as `jwt_maxage` (default `60`)
+    pub fn init() -> Config {
+        let jwt_secret =
+            std::env::var("JWT_SECRET").expect("JWT_SECRET environment variable not set");
+
+        let jwt_expires_in = std::env::var("JWT_EXPIRES_IN_SECONDS")
+            .ok()
+            .and_then(|v| v.parse::<i64>().ok())
+            .unwrap_or(900);
+
+        let jwt_maxage = std::env::var("JWT_MAXAGE_MINUTES")
+            .ok()
+            .and_then(|v| v.parse::<i64>().ok())
+            .unwrap_or(60);
+
+        Config {
+            jwt_secret,
+            jwt_expires_in,
+            jwt_maxage,
+        }
+    }
+}
+
+/// Request body for `POST /auth/login`
+#[derive(Deserialize, ToSchema)]
+pub struct LoginInput {
+    pub username: String,
+    pub password: String,
+}
+
+/// Response body for `POST /auth/login`
+#[derive(Serialize, ToSchema)]
+pub struct TokenResponse {
+    /// HS256-signed JWT to send as `Authorization: Bearer <token>`
+    pub token: String,
+}
+
+/// POST /auth/login - Verify credentials and issue a JWT
+///
+/// Looks up the user by username and checks the password against the stored
+/// bcrypt hash, returning an HS256 token whose claims carry the user id as `sub`.
+///
+/// # Returns
+/// - `200 OK`: `{ "token": "..." }`
+/// - `401 Unauthorized`: unknown username or wrong password
+/// - `500 Internal Server Error`: database or token-signing failure
+#[utoipa::path(
+    post,
+    path = "/auth/login",
+    tag = "auth",
+    request_body = LoginInput,
+    responses(
+        (status = 200, description = "Issued a JWT", body = TokenResponse),
+        (status = 401, description = "Invalid username or password"),
+        (status = 500, description = "Database or token-signing failure"),
+    )
+)]
+pub async fn login(
+    pool: web::Data<DbClient>,
+    config: web::Data<Config>,
+    body: web::Json<LoginInput>,
+) -> HttpResponse {
+    log::info!("POST /auth/login endpoint called for username={}", body.username);
+
+    let mut conn = match pool.get().await {
+        Ok(conn) => conn,
+        Err(e) => {
+            log::error!("Failed to get DB connection from pool: {}", e);
+            return HttpResponse::InternalServerError().json("Database connection failed");
+        }
+    };
+
+    let mut query = Query::new("SELECT id, password_hash FROM users WHERE username = @P1");
+    query.bind(body.username.clone());
+
+    let row = match query.query(&mut *conn).await {
+        Ok(stream) => stream.into_row().await,
+        Err(e) => {
+            log::error!("DB error on login: {}", e);
+            return HttpResponse::InternalServerError().json("Database query failed");
+        }
+    };
+
+    let row = match row {
+        Ok(Some(row)) => row,
+        Ok(None) => {
+            log::warn!("Login failed: unknown username {}", body.username);
+            return HttpResponse::Unauthorized().json("Invalid username or password");
+        }
+        Err(e) => {
+            log::error!("Failed to fetch row: {}", e);
+            return HttpResponse::InternalServerError().json("Failed to process query results");
+        }
+    };
+
+    let user_id = row.get::<i32, _>("id").unwrap_or(0);
+    let password_hash: &str = row.get("password_hash").unwrap_or("");
+
+    match bcrypt::verify(&body.password, password_hash) {
+        Ok(true) => {}
+        Ok(false) => {
+            log::warn!("Login failed: bad password for username {}", body.username);
+            return HttpResponse::Unauthorized().json("Invalid username or password");
+        }
+        Err(e) => {
+            log::error!("Failed to verify password hash: {}", e);
+            return HttpResponse::InternalServerError().json("Failed to verify credentials");
+        }
+    }
+
+    let now = chrono::Utc::now();
+    let claims = Claims {
+        sub: user_id.to_string(),
+        iat: now.timestamp() as usize,
+        exp: (now + chrono::Duration::seconds(config.jwt_expires_in)).timestamp() as usize,
+    };
+
+    let token = match encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(config.jwt_secret.as_bytes()),
+    ) {
+        Ok(token) => token,
+        Err(e) => {
+            log::error!("Failed to sign JWT: {}", e);
+            return HttpResponse::InternalServerError().json("Failed to issue token");
+        }
+    };
+
+    log::info!("Issued token for user id={}", user_id);
+    HttpResponse::Ok().json(TokenResponse { token })
+}
+
+/// Authenticated user extracted from a valid `Authorization: Bearer` JWT
+///
+/// Add this as a handler parameter to require a valid token; Actix rejects the
+/// request with `401` before the handler body runs if extraction fails.
+pub struct AuthUser {
+    pub user_id: i32,
+}
+
+impl FromRequest for AuthUser {
+    type Error = ActixError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        let config = match req.app_data::<web::Data<Config>>() {
+            Some(config) => config,
+            None => {
+                return ready(Err(actix_web::error::ErrorInternalServerError(
+                    "JWT config missing",
+                )))
+            }
+        };
+
+        let token = req
+            .headers()
+            .get("Authorization")
+            .and_then(|h| h.to_str().ok())
+            .and_then(|h| h.strip_prefix("Bearer "));
+
+        let token = match token {
+            Some(token) => token,
+            None => {
+                return ready(Err(actix_web::error::ErrorUnauthorized(
+                    "Missing bearer token",
+                )))
+            }
+        };
+
+        match decode::<Claims>(
+            token,
+            &DecodingKey::from_secret(config.jwt_secret.as_bytes()),
+            &Validation::default(),
+        ) {
+            Ok(data) => match data.claims.sub.parse::<i32>() {
+                Ok(user_id) => ready(Ok(AuthUser { user_id })),
+                Err(_) => ready(Err(actix_web::error::ErrorUnauthorized(
+                    "Invalid token subject",
+                ))),
+            },
+            Err(e) => {
+                log::warn!("JWT validation failed: {}", e);
+                ready(Err(actix_web::error::ErrorUnauthorized(
+                    "Invalid or expired token",
+                )))
+            }
+        }
+    }
+}
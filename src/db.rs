@@ -1,86 +1,155 @@
 /// Database module for MSSQL connectivity using Tiberius
-/// 
-/// This module handles database connection setup and configuration
-/// for connecting to Microsoft SQL Server using the Tiberius driver.
+///
+/// This module owns a `bb8` connection pool so concurrent request handlers
+/// can each check out an independent connection instead of serializing every
+/// query through a single shared client.
 
-use tiberius::{Client, Config, AuthMethod};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use bb8::Pool;
+use tiberius::{AuthMethod, Client, Config, Query};
 use tokio::net::TcpStream;
-use tokio_util::compat::{TokioAsyncWriteCompatExt, Compat};
-use std::sync::Arc;
-
-/// Type alias for the database client wrapped in Arc<Mutex<>> for thread-safe sharing
-/// 
-/// - Arc: Allows multiple references to the same data across threads
-/// - Mutex: Provides thread-safe access to the database client
-/// - Client<Compat<TcpStream>>: Tiberius client with async TCP stream
-pub type DbClient = Arc<tokio::sync::Mutex<Client<Compat<TcpStream>>>>;
-
-/// Initialize database connection to MSSQL Server
-/// 
-/// Creates a connection to the MSSQL database using configuration values from environment variables.
-/// This allows for flexible configuration across different environments (development, staging, production).
-/// 
+use tokio_util::compat::{Compat, TokioAsyncWriteCompatExt};
+
+/// Type alias for the pooled database client shared across handlers
+///
+/// - `bb8::Pool`: hands out and recycles connections across threads
+/// - `TiberiusConnectionManager`: knows how to open and health-check a connection
+pub type DbClient = Pool<TiberiusConnectionManager>;
+
+/// `bb8::ManageConnection` implementation for Tiberius/MSSQL connections
+///
+/// Holds the `Config` built once from environment variables in [`init_db`] and
+/// reuses it to open every connection the pool needs, including replacements
+/// for ones that fail their health check.
+#[derive(Clone)]
+pub struct TiberiusConnectionManager {
+    config: Config,
+}
+
+impl TiberiusConnectionManager {
+    fn new(config: Config) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait]
+impl bb8::ManageConnection for TiberiusConnectionManager {
+    type Connection = Client<Compat<TcpStream>>;
+    type Error = tiberius::error::Error;
+
+    async fn connect(&self) -> Result<Self::Connection, Self::Error> {
+        // Establish TCP connection to the database server
+        let tcp = TcpStream::connect(self.config.get_addr())
+            .await
+            .map_err(tiberius::error::Error::Io)?;
+
+        // Enable TCP_NODELAY for better performance with small packets
+        tcp.set_nodelay(true).map_err(tiberius::error::Error::Io)?;
+
+        // Create the Tiberius client with the TCP connection
+        Client::connect(self.config.clone(), tcp.compat_write()).await
+    }
+
+    async fn is_valid(&self, conn: &mut Self::Connection) -> Result<(), Self::Error> {
+        // Cheap round-trip used to evict dead connections before handing them out
+        Query::new("SELECT 1").query(conn).await?.into_results().await?;
+        Ok(())
+    }
+
+    fn has_broken(&self, _conn: &mut Self::Connection) -> bool {
+        false
+    }
+}
+
+/// Initialize the database connection pool to MSSQL Server
+///
+/// Creates a `bb8` pool backed by [`TiberiusConnectionManager`], configured from
+/// environment variables so it can be tuned per environment (development, staging,
+/// production).
+///
 /// # Environment Variables Required
 /// - `DB_HOST`: Database server hostname or IP address
 /// - `DB_PORT`: Database server port (typically 1433 for MSSQL)
 /// - `DB_NAME`: Target database name
 /// - `DB_USER`: Database username for authentication
 /// - `DB_PASSWORD`: Database password for authentication
-/// 
+///
+/// # Environment Variables Optional
+/// - `DB_POOL_MAX`: Maximum number of pooled connections (default `10`)
+/// - `DB_CONNECT_TIMEOUT`: Seconds to wait for a new connection before failing (default `5`)
+///
 /// # Returns
-/// - `Ok(DbClient)`: Successfully connected database client
+/// - `Ok(DbClient)`: Successfully initialized connection pool
 /// - `Err(Box<dyn std::error::Error>)`: Connection failed or missing environment variables
-/// 
+///
 /// # Example
 /// ```rust
-/// let client = init_db().await?;
+/// let pool = init_db().await?;
+/// let mut conn = pool.get().await?;
 /// ```
 pub async fn init_db() -> Result<DbClient, Box<dyn std::error::Error>> {
     // Read database configuration from environment variables
     let db_host = std::env::var("DB_HOST")
         .map_err(|_| "DB_HOST environment variable not set")?;
-    
+
     let db_port = std::env::var("DB_PORT")
         .map_err(|_| "DB_PORT environment variable not set")?
         .parse::<u16>()
         .map_err(|_| "DB_PORT must be a valid port number")?;
-    
+
     let db_name = std::env::var("DB_NAME")
         .map_err(|_| "DB_NAME environment variable not set")?;
-    
+
     let db_user = std::env::var("DB_USER")
         .map_err(|_| "DB_USER environment variable not set")?;
-    
+
     let db_password = std::env::var("DB_PASSWORD")
         .map_err(|_| "DB_PASSWORD environment variable not set")?;
 
+    // Pool tuning knobs, both optional with sensible defaults
+    let pool_max = std::env::var("DB_POOL_MAX")
+        .ok()
+        .and_then(|v| v.parse::<u32>().ok())
+        .unwrap_or(10);
+
+    let connect_timeout_secs = std::env::var("DB_CONNECT_TIMEOUT")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(5);
+
     let mut config = Config::new();
-    
+
     // Configure database connection using environment variables
     config.host(&db_host);
     config.port(db_port);
     config.database(&db_name);
-    
+
     // SQL Server authentication with credentials from environment
     config.authentication(AuthMethod::sql_server(&db_user, &db_password));
-    
+
     // Trust the server certificate (for development only)
     // In production, use proper certificate validation
     config.trust_cert();
 
-    log::info!("Connecting to MSSQL at {}:{} database: {}", db_host, db_port, db_name);
-
-    // Establish TCP connection to the database server
-    let tcp = TcpStream::connect(config.get_addr()).await?;
-    
-    // Enable TCP_NODELAY for better performance with small packets
-    tcp.set_nodelay(true)?;
-
-    // Create the Tiberius client with the TCP connection
-    let client = Client::connect(config, tcp.compat_write()).await?;
-    
-    log::info!("Successfully connected to MSSQL database");
-    
-    // Wrap the client in Arc<Mutex<>> for thread-safe sharing across handlers
-    Ok(Arc::new(tokio::sync::Mutex::new(client)))
+    log::info!(
+        "Connecting to MSSQL at {}:{} database: {} (pool_max={}, connect_timeout={}s)",
+        db_host, db_port, db_name, pool_max, connect_timeout_secs
+    );
+
+    let manager = TiberiusConnectionManager::new(config);
+
+    // Keep one connection open so `build` connects now and fails fast if the
+    // database is unreachable
+    let pool = Pool::builder()
+        .max_size(pool_max)
+        .min_idle(Some(1))
+        .connection_timeout(Duration::from_secs(connect_timeout_secs))
+        .build(manager)
+        .await?;
+
+    log::info!("Database connection pool ready");
+
+    Ok(pool)
 }
@@ -5,74 +5,119 @@
 /// request parameters and returns an HttpResponse.
 
 use actix_web::{web, HttpResponse};
+use chrono::{TimeZone, Utc};
 use tiberius::{Query, Row};
 
-use crate::models::User;
+use crate::auth::AuthUser;
 use crate::db::DbClient;
+use crate::models::{CreateUser, HealthStatus, UpdateUser, User};
+
+/// Build a [`User`] from a result row shared by the `id, username, email, full_name,
+/// created_at, updated_at` column set
+///
+/// Centralizes the column extraction used by every handler that reads users back
+/// from the database, so adding/renaming a column only needs to change in one place.
+fn row_to_user(row: &Row) -> User {
+    User {
+        id: row.get::<i32, _>("id").unwrap_or(0),
+        username: row.get::<&str, _>("username").unwrap_or("").to_string(),
+        email: row.get::<&str, _>("email").unwrap_or("").to_string(),
+        full_name: row.get::<&str, _>("full_name").unwrap_or("").to_string(),
+        created_at: row
+            .get::<chrono::NaiveDateTime, _>("created_at")
+            .map(|ndt| Utc.from_utc_datetime(&ndt)),
+        updated_at: row
+            .get::<chrono::NaiveDateTime, _>("updated_at")
+            .map(|ndt| Utc.from_utc_datetime(&ndt)),
+    }
+}
+
+/// Check whether a Tiberius error is a SQL Server unique constraint/index violation
+///
+/// SQL Server reports duplicate keys as error 2627 (UNIQUE/PK constraint) or 2601
+/// (duplicate key in a unique index) — both map to `409 Conflict` rather than `500`.
+fn is_unique_violation(err: &tiberius::error::Error) -> bool {
+    matches!(err, tiberius::error::Error::Server(e) if e.code() == 2627 || e.code() == 2601)
+}
 
 /// GET /users - Retrieve all users from the database
-/// 
+///
 /// This endpoint fetches all users from the MSSQL 'users' table and returns them as JSON.
 /// It demonstrates basic database querying with proper error handling and logging.
-/// 
+///
 /// # Parameters
-/// - `client`: Shared database client wrapped in Arc<Mutex<>> for thread-safe access
-/// 
+/// - `pool`: Shared `bb8` connection pool; a connection is checked out for the
+///   duration of the query and returned to the pool when it drops
+///
 /// # Returns
 /// - `200 OK`: JSON array of user objects on success
 /// - `500 Internal Server Error`: JSON error message on database or processing failure
-/// 
+///
 /// # Example Response
 /// ```json
 /// [
 ///   {
 ///     "id": 1,
 ///     "username": "john_doe",
-///     "email": "john@example.com", 
+///     "email": "john@example.com",
 ///     "full_name": "John Doe",
-///     "created_at": null,
-///     "updated_at": null
+///     "created_at": "2025-10-26T10:30:00Z",
+///     "updated_at": "2025-10-26T10:30:00Z"
 ///   }
 /// ]
 /// ```
-/// 
+///
 /// # Error Handling
-/// - Database connection failures return 500 with error message
+/// - Pool checkout failures return 500 with error message
 /// - Query execution failures are logged and return 500
 /// - Result processing failures are logged and return 500
-/// 
+///
 /// # Notes
-/// - DateTime fields (created_at, updated_at) are currently set to null due to 
-///   datetime conversion complexity with Tiberius
-/// - Database connection is properly released after query execution
+/// - The connection is returned to the pool as soon as it goes out of scope
 /// - All operations are logged for debugging purposes
-pub async fn get_users(client: web::Data<DbClient>) -> HttpResponse {
+#[utoipa::path(
+    get,
+    path = "/users",
+    tag = "users",
+    responses(
+        (status = 200, description = "List all users", body = [User]),
+        (status = 401, description = "Missing or invalid bearer token"),
+        (status = 500, description = "Database or processing failure"),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_users(pool: web::Data<DbClient>, _auth: AuthUser) -> HttpResponse {
     log::info!("GET /users endpoint called");
-    
-    // Prepare SQL query to fetch user data (excluding datetime fields for now)
-    let query = Query::new("SELECT id, username, email, full_name FROM users");
-    
-    // Execute query while holding database connection lock
-    // Use a separate scope to ensure the lock is released promptly
-    let results = {
-        let mut client_guard = client.lock().await;
-        log::info!("Database connection acquired");
-        
-        // Execute the query and get a stream of results
-        let stream = match query.query(&mut *client_guard).await {
-            Ok(stream) => {
-                log::info!("Query executed successfully");
-                stream
-            },
-            Err(e) => {
-                log::error!("DB error: {}", e);
-                return HttpResponse::InternalServerError().json("Database query failed");
-            }
-        };
-        
-        // Convert the stream to concrete results
-        stream.into_results().await
-    }; // client_guard is automatically dropped here, releasing the database connection
+
+    // Check out a connection from the pool; handlers no longer share one mutex
+    let mut conn = match pool.get().await {
+        Ok(conn) => conn,
+        Err(e) => {
+            log::error!("Failed to get DB connection from pool: {}", e);
+            return HttpResponse::InternalServerError().json("Database connection failed");
+        }
+    };
+    log::info!("Database connection acquired from pool");
+
+    // Prepare SQL query to fetch user data, including audit timestamps
+    let query = Query::new(
+        "SELECT id, username, email, full_name, created_at, updated_at FROM users",
+    );
+
+    // Execute the query and get a stream of results
+    let stream = match query.query(&mut *conn).await {
+        Ok(stream) => {
+            log::info!("Query executed successfully");
+            stream
+        },
+        Err(e) => {
+            log::error!("DB error: {}", e);
+            return HttpResponse::InternalServerError().json("Database query failed");
+        }
+    };
+
+    // Convert the stream to concrete results; `conn` is returned to the pool once dropped
+    let results = stream.into_results().await;
     
     // Process the query results
     match results {
@@ -85,18 +130,7 @@ pub async fn get_users(client: web::Data<DbClient>) -> HttpResponse {
             log::info!("Found {} rows", rows.len());
             
             // Convert database rows to User structs
-            let users: Vec<User> = rows.iter().map(|row| {
-                User {
-                    // Extract column values with fallback defaults for safety
-                    id: row.get::<i32, _>("id").unwrap_or(0),
-                    username: row.get::<&str, _>("username").unwrap_or("").to_string(),
-                    email: row.get::<&str, _>("email").unwrap_or("").to_string(),
-                    full_name: row.get::<&str, _>("full_name").unwrap_or("").to_string(),
-                    // Skip datetime conversion for now due to Tiberius complexity
-                    created_at: None,
-                    updated_at: None,
-                }
-            }).collect();
+            let users: Vec<User> = rows.iter().map(row_to_user).collect();
             
             log::info!("Returning {} users", users.len());
             HttpResponse::Ok().json(users)
@@ -108,24 +142,363 @@ pub async fn get_users(client: web::Data<DbClient>) -> HttpResponse {
     }
 }
 
-/// GET /health - Health check endpoint
-/// 
-/// A simple endpoint to verify that the server is running and responding to requests.
-/// This is useful for load balancers, monitoring systems, and deployment health checks.
-/// 
+/// POST /users - Create a new user
+///
+/// Inserts a row into the MSSQL 'users' table using a parameterized query and
+/// `OUTPUT INSERTED.*` to read the persisted row — including the generated id
+/// and audit timestamps — back in the same round trip.
+///
+/// # Returns
+/// - `201 Created`: JSON body of the newly created user
+/// - `409 Conflict`: `username` or `email` already exists
+/// - `500 Internal Server Error`: database or processing failure
+#[utoipa::path(
+    post,
+    path = "/users",
+    tag = "users",
+    request_body = CreateUser,
+    responses(
+        (status = 201, description = "User created", body = User),
+        (status = 401, description = "Missing or invalid bearer token"),
+        (status = 409, description = "Username or email already exists"),
+        (status = 500, description = "Database or processing failure"),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn create_user(
+    pool: web::Data<DbClient>,
+    body: web::Json<CreateUser>,
+    _auth: AuthUser,
+) -> HttpResponse {
+    log::info!("POST /users endpoint called for username={}", body.username);
+
+    let password_hash = match bcrypt::hash(&body.password, bcrypt::DEFAULT_COST) {
+        Ok(hash) => hash,
+        Err(e) => {
+            log::error!("Failed to hash password on create: {}", e);
+            return HttpResponse::InternalServerError().json("Failed to create user");
+        }
+    };
+
+    let mut conn = match pool.get().await {
+        Ok(conn) => conn,
+        Err(e) => {
+            log::error!("Failed to get DB connection from pool: {}", e);
+            return HttpResponse::InternalServerError().json("Database connection failed");
+        }
+    };
+
+    // OUTPUT INSERTED.* hands back the full persisted row, timestamps included,
+    // in the same round trip as the insert
+    let mut query = Query::new(
+        "INSERT INTO users (username, email, full_name, password_hash) \
+         OUTPUT INSERTED.* \
+         VALUES (@P1, @P2, @P3, @P4)",
+    );
+    query.bind(body.username.clone());
+    query.bind(body.email.clone());
+    query.bind(body.full_name.clone());
+    query.bind(password_hash);
+
+    let row = match query.query(&mut *conn).await {
+        Ok(stream) => stream.into_row().await,
+        Err(e) if is_unique_violation(&e) => {
+            log::warn!("Duplicate username/email on create: {}", e);
+            return HttpResponse::Conflict().json("Username or email already exists");
+        }
+        Err(e) => {
+            log::error!("DB error on create: {}", e);
+            return HttpResponse::InternalServerError().json("Database query failed");
+        }
+    };
+
+    match row {
+        Ok(Some(row)) => {
+            let user = row_to_user(&row);
+            log::info!("Created user id={}", user.id);
+            HttpResponse::Created().json(user)
+        }
+        Ok(None) => {
+            log::error!("Insert did not return the inserted row");
+            HttpResponse::InternalServerError().json("Failed to create user")
+        }
+        Err(e) => {
+            log::error!("Failed to read inserted row: {}", e);
+            HttpResponse::InternalServerError().json("Failed to process query results")
+        }
+    }
+}
+
+/// GET /users/{id} - Retrieve a single user by id
+///
+/// # Returns
+/// - `200 OK`: JSON body of the matching user
+/// - `404 Not Found`: no user with that id
+/// - `500 Internal Server Error`: database or processing failure
+#[utoipa::path(
+    get,
+    path = "/users/{id}",
+    tag = "users",
+    params(("id" = i32, Path, description = "User id")),
+    responses(
+        (status = 200, description = "The matching user", body = User),
+        (status = 401, description = "Missing or invalid bearer token"),
+        (status = 404, description = "No user with that id"),
+        (status = 500, description = "Database or processing failure"),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_user(
+    pool: web::Data<DbClient>,
+    path: web::Path<i32>,
+    _auth: AuthUser,
+) -> HttpResponse {
+    let id = path.into_inner();
+    log::info!("GET /users/{} endpoint called", id);
+
+    let mut conn = match pool.get().await {
+        Ok(conn) => conn,
+        Err(e) => {
+            log::error!("Failed to get DB connection from pool: {}", e);
+            return HttpResponse::InternalServerError().json("Database connection failed");
+        }
+    };
+
+    let mut query = Query::new(
+        "SELECT id, username, email, full_name, created_at, updated_at FROM users WHERE id = @P1",
+    );
+    query.bind(id);
+
+    let row = match query.query(&mut *conn).await {
+        Ok(stream) => stream.into_row().await,
+        Err(e) => {
+            log::error!("DB error on get: {}", e);
+            return HttpResponse::InternalServerError().json("Database query failed");
+        }
+    };
+
+    match row {
+        Ok(Some(row)) => HttpResponse::Ok().json(row_to_user(&row)),
+        Ok(None) => HttpResponse::NotFound().json("User not found"),
+        Err(e) => {
+            log::error!("Failed to fetch row: {}", e);
+            HttpResponse::InternalServerError().json("Failed to process query results")
+        }
+    }
+}
+
+/// PUT /users/{id} - Replace an existing user's fields
+///
+/// # Returns
+/// - `200 OK`: JSON body of the updated user
+/// - `404 Not Found`: no user with that id
+/// - `409 Conflict`: `username` or `email` already used by another user
+/// - `500 Internal Server Error`: database or processing failure
+#[utoipa::path(
+    put,
+    path = "/users/{id}",
+    tag = "users",
+    params(("id" = i32, Path, description = "User id")),
+    request_body = UpdateUser,
+    responses(
+        (status = 200, description = "The updated user", body = User),
+        (status = 401, description = "Missing or invalid bearer token"),
+        (status = 404, description = "No user with that id"),
+        (status = 409, description = "Username or email already used by another user"),
+        (status = 500, description = "Database or processing failure"),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn update_user(
+    pool: web::Data<DbClient>,
+    path: web::Path<i32>,
+    body: web::Json<UpdateUser>,
+    _auth: AuthUser,
+) -> HttpResponse {
+    let id = path.into_inner();
+    log::info!("PUT /users/{} endpoint called", id);
+
+    let mut conn = match pool.get().await {
+        Ok(conn) => conn,
+        Err(e) => {
+            log::error!("Failed to get DB connection from pool: {}", e);
+            return HttpResponse::InternalServerError().json("Database connection failed");
+        }
+    };
+
+    // OUTPUT INSERTED.* hands back the row as it now stands, so the response
+    // reflects the `updated_at = GETUTCDATE()` this statement just set
+    let mut query = Query::new(
+        "UPDATE users SET username = @P1, email = @P2, full_name = @P3, updated_at = GETUTCDATE() \
+         OUTPUT INSERTED.* \
+         WHERE id = @P4",
+    );
+    query.bind(body.username.clone());
+    query.bind(body.email.clone());
+    query.bind(body.full_name.clone());
+    query.bind(id);
+
+    let row = match query.query(&mut *conn).await {
+        Ok(stream) => stream.into_row().await,
+        Err(e) if is_unique_violation(&e) => {
+            log::warn!("Duplicate username/email on update: {}", e);
+            return HttpResponse::Conflict().json("Username or email already exists");
+        }
+        Err(e) => {
+            log::error!("DB error on update: {}", e);
+            return HttpResponse::InternalServerError().json("Database query failed");
+        }
+    };
+
+    match row {
+        Ok(Some(row)) => {
+            let user = row_to_user(&row);
+            log::info!("Updated user id={}", user.id);
+            HttpResponse::Ok().json(user)
+        }
+        Ok(None) => HttpResponse::NotFound().json("User not found"),
+        Err(e) => {
+            log::error!("Failed to read updated row: {}", e);
+            HttpResponse::InternalServerError().json("Failed to process query results")
+        }
+    }
+}
+
+/// DELETE /users/{id} - Remove a user
+///
+/// # Returns
+/// - `204 No Content`: user deleted
+/// - `404 Not Found`: no user with that id
+/// - `500 Internal Server Error`: database failure
+#[utoipa::path(
+    delete,
+    path = "/users/{id}",
+    tag = "users",
+    params(("id" = i32, Path, description = "User id")),
+    responses(
+        (status = 204, description = "User deleted"),
+        (status = 401, description = "Missing or invalid bearer token"),
+        (status = 404, description = "No user with that id"),
+        (status = 500, description = "Database failure"),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_user(
+    pool: web::Data<DbClient>,
+    path: web::Path<i32>,
+    _auth: AuthUser,
+) -> HttpResponse {
+    let id = path.into_inner();
+    log::info!("DELETE /users/{} endpoint called", id);
+
+    let mut conn = match pool.get().await {
+        Ok(conn) => conn,
+        Err(e) => {
+            log::error!("Failed to get DB connection from pool: {}", e);
+            return HttpResponse::InternalServerError().json("Database connection failed");
+        }
+    };
+
+    let mut query = Query::new("DELETE FROM users WHERE id = @P1");
+    query.bind(id);
+
+    let result = match query.execute(&mut *conn).await {
+        Ok(result) => result,
+        Err(e) => {
+            log::error!("DB error on delete: {}", e);
+            return HttpResponse::InternalServerError().json("Database query failed");
+        }
+    };
+
+    if result.rows_affected().iter().sum::<u64>() == 0 {
+        return HttpResponse::NotFound().json("User not found");
+    }
+
+    log::info!("Deleted user id={}", id);
+    HttpResponse::NoContent().finish()
+}
+
+/// GET /health - Liveness check endpoint
+///
+/// A cheap endpoint to verify that the process is running and responding to
+/// requests. This is useful for load balancers, monitoring systems, and
+/// deployment health checks that just need to know the process is alive.
+///
 /// # Returns
 /// - `200 OK`: JSON message confirming server is running
-/// 
+///
 /// # Example Response
 /// ```json
 /// "Server is running!"
 /// ```
-/// 
+///
 /// # Notes
 /// - This endpoint does not check database connectivity
-/// - For a full health check including database, consider adding database ping
-/// - Always returns success unless the server is completely down
+/// - For a check that verifies the database is reachable, see [`health_ready`]
+/// - Always returns success unless the process is completely down
+#[utoipa::path(
+    get,
+    path = "/health",
+    tag = "health",
+    responses((status = 200, description = "Server is running"))
+)]
 pub async fn health_check() -> HttpResponse {
     log::info!("Health check endpoint called");
     HttpResponse::Ok().json("Server is running!")
 }
+
+/// GET /health/ready - Readiness check endpoint
+///
+/// Borrows a connection from the pool and runs a trivial `SELECT 1` with a short
+/// timeout, so load balancers and orchestrators can tell "process alive" apart
+/// from "able to serve traffic".
+///
+/// # Returns
+/// - `200 OK`: `{"status":"ok","database":"up"}`
+/// - `503 Service Unavailable`: `{"status":"error","database":"down"}`
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    tag = "health",
+    responses(
+        (status = 200, description = "Database reachable", body = HealthStatus),
+        (status = 503, description = "Database unreachable", body = HealthStatus),
+    )
+)]
+pub async fn health_ready(pool: web::Data<DbClient>) -> HttpResponse {
+    log::info!("GET /health/ready endpoint called");
+
+    let probe = tokio::time::timeout(std::time::Duration::from_secs(2), async {
+        let mut conn = pool.get().await.map_err(|e| e.to_string())?;
+        Query::new("SELECT 1")
+            .query(&mut *conn)
+            .await
+            .map_err(|e| e.to_string())?
+            .into_results()
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok::<(), String>(())
+    })
+    .await;
+
+    match probe {
+        Ok(Ok(())) => HttpResponse::Ok().json(HealthStatus {
+            status: "ok".to_string(),
+            database: "up".to_string(),
+        }),
+        Ok(Err(e)) => {
+            log::error!("Readiness check failed: {}", e);
+            HttpResponse::ServiceUnavailable().json(HealthStatus {
+                status: "error".to_string(),
+                database: "down".to_string(),
+            })
+        }
+        Err(_) => {
+            log::error!("Readiness check timed out");
+            HttpResponse::ServiceUnavailable().json(HealthStatus {
+                status: "error".to_string(),
+                database: "down".to_string(),
+            })
+        }
+    }
+}
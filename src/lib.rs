@@ -0,0 +1,111 @@
+// Module declarations for database, handlers, models, and authentication
+pub mod auth;
+pub mod db;
+pub mod handlers;
+pub mod models;
+
+use actix_web::{dev::Server, web, App, HttpServer};
+use actix_web::middleware::Logger;
+use std::net::TcpListener;
+use utoipa::{
+    openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme},
+    Modify, OpenApi,
+};
+use utoipa_swagger_ui::SwaggerUi;
+
+use crate::db::DbClient;
+
+/// Aggregates every handler's `#[utoipa::path]` and model's `#[derive(ToSchema)]`
+/// into one OpenAPI 3 document, served at `/api-docs/openapi.json` and rendered
+/// at `/swagger-ui`.
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handlers::health_check,
+        handlers::health_ready,
+        handlers::get_users,
+        handlers::create_user,
+        handlers::get_user,
+        handlers::update_user,
+        handlers::delete_user,
+        auth::login,
+    ),
+    components(schemas(
+        models::User,
+        models::CreateUser,
+        models::UpdateUser,
+        models::HealthStatus,
+        auth::LoginInput,
+        auth::TokenResponse,
+    )),
+    modifiers(&SecurityAddon),
+    tags(
+        (name = "users", description = "User CRUD endpoints"),
+        (name = "auth", description = "Authentication endpoints"),
+        (name = "health", description = "Liveness and readiness probes"),
+    )
+)]
+struct ApiDoc;
+
+/// Registers the `bearer_auth` security scheme referenced by protected routes
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        if let Some(components) = openapi.components.as_mut() {
+            components.add_security_scheme(
+                "bearer_auth",
+                SecurityScheme::Http(
+                    HttpBuilder::new()
+                        .scheme(HttpAuthScheme::Bearer)
+                        .bearer_format("JWT")
+                        .build(),
+                ),
+            );
+        }
+    }
+}
+
+/// Build the Actix `App` and start serving on an already-bound listener
+///
+/// Taking the `TcpListener` rather than an address lets callers bind to port
+/// `0` and let the OS pick a free port, which is what the integration tests
+/// use to run many servers side by side without port clashes.
+///
+/// # Parameters
+/// - `listener`: a already-bound TCP listener (bind to port `0` for an ephemeral port)
+/// - `pool`: the `bb8` database connection pool shared across handlers
+/// - `config`: JWT settings shared with the login handler and `AuthUser` extractor
+pub fn run(
+    listener: TcpListener,
+    pool: DbClient,
+    config: auth::Config,
+) -> Result<Server, std::io::Error> {
+    let server = HttpServer::new(move || {
+        App::new()
+            // Share the connection pool across all handlers
+            .app_data(web::Data::new(pool.clone()))
+            // Share JWT configuration with the login handler and AuthUser extractor
+            .app_data(web::Data::new(config.clone()))
+            // Add request logging middleware
+            .wrap(Logger::default())
+            // API Routes
+            .route("/health", web::get().to(handlers::health_check)) // Liveness probe
+            .route("/health/ready", web::get().to(handlers::health_ready)) // Readiness probe (checks DB)
+            .route("/auth/login", web::post().to(auth::login)) // Issue a JWT endpoint
+            .route("/users", web::get().to(handlers::get_users)) // Get all users endpoint (protected)
+            .route("/users", web::post().to(handlers::create_user)) // Create a user endpoint (protected)
+            .route("/users/{id}", web::get().to(handlers::get_user)) // Get a single user endpoint (protected)
+            .route("/users/{id}", web::put().to(handlers::update_user)) // Update a user endpoint (protected)
+            .route("/users/{id}", web::delete().to(handlers::delete_user)) // Delete a user endpoint (protected)
+            // Swagger UI + raw spec, generated from the annotations above
+            .service(
+                SwaggerUi::new("/swagger-ui/{_:.*}")
+                    .url("/api-docs/openapi.json", ApiDoc::openapi()),
+            )
+    })
+    .listen(listener)?
+    .run();
+
+    Ok(server)
+}
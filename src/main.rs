@@ -1,43 +1,37 @@
-// Module declarations for database, handlers, and models
-mod db;
-mod handlers;
-mod models;
+use std::net::TcpListener;
 
-use actix_web::{web, App, HttpServer, middleware::Logger};
 use dotenvy::dotenv;
+use rust_rest_apis::{auth, db, run};
 
 /// Main entry point for the Rust REST API application
-/// 
+///
 /// This application provides a RESTful API for user management using:
 /// - Actix-web as the web framework
 /// - Tiberius for MSSQL database connectivity
+/// - JWT-based authentication on the `/users` routes
 /// - Environment-based configuration
+/// - A `utoipa`-generated OpenAPI spec served via Swagger UI
+///
+/// The `App` factory and server setup live in [`rust_rest_apis::run`] so
+/// integration tests can boot the exact same server on an ephemeral port.
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
     // Load environment variables from .env file
     dotenv().ok();
-    
+
     // Initialize logging (controlled by RUST_LOG environment variable)
     env_logger::init();
 
     // Initialize database connection pool
     // This will panic if database connection fails - appropriate for startup
-    let client = db::init_db().await.expect("Failed to connect to database");
+    let pool = db::init_db().await.expect("Failed to connect to database");
+
+    // Load JWT configuration once at startup
+    // This will panic if JWT_SECRET is unset - appropriate for startup
+    let config = auth::Config::init();
 
-    log::info!("Starting server at http://127.0.0.1:8080");
+    let listener = TcpListener::bind("127.0.0.1:8080")?;
+    log::info!("Starting server at http://{}", listener.local_addr()?);
 
-    // Create and configure the HTTP server
-    HttpServer::new(move || {
-        App::new()
-            // Share database client across all handlers
-            .app_data(web::Data::new(client.clone()))
-            // Add request logging middleware
-            .wrap(Logger::default())
-            // API Routes
-            .route("/health", web::get().to(handlers::health_check))  // Health check endpoint
-            .route("/users", web::get().to(handlers::get_users))     // Get all users endpoint
-    })
-    .bind("127.0.0.1:8080")?  // Bind to localhost on port 8080
-    .run()                    // Start the server
-    .await
+    run(listener, pool, config)?.await
 }
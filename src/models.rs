@@ -1,9 +1,11 @@
 /// Data models for the REST API
-/// 
+///
 /// This module contains the data structures used throughout the application.
-/// All models implement Serialize for JSON response serialization.
+/// Response models implement `Serialize`; request bodies implement `Deserialize`.
 
-use serde::Serialize;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// User data model representing a user in the system
 /// 
@@ -12,6 +14,7 @@ use serde::Serialize;
 /// - username: NVARCHAR(50) NOT NULL UNIQUE
 /// - email: NVARCHAR(100) NOT NULL UNIQUE  
 /// - full_name: NVARCHAR(100) NOT NULL
+/// - password_hash: NVARCHAR(255) NOT NULL (bcrypt, checked by `POST /auth/login`)
 /// - created_at: DATETIME2 DEFAULT GETUTCDATE()
 /// - updated_at: DATETIME2 DEFAULT GETUTCDATE()
 /// 
@@ -37,7 +40,7 @@ use serde::Serialize;
 ///   "updated_at": "2025-10-26T10:30:00"
 /// }
 /// ```
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 pub struct User {
     /// Unique user identifier
     pub id: i32,
@@ -51,11 +54,74 @@ pub struct User {
     /// User's full display name
     pub full_name: String,
     
-    /// Optional timestamp when user was created
-    /// Note: Currently skipped in API responses due to datetime conversion complexity
-    pub created_at: Option<String>,
-    
-    /// Optional timestamp when user was last updated
-    /// Note: Currently skipped in API responses due to datetime conversion complexity
-    pub updated_at: Option<String>,
+    /// Timestamp when the user was created, serialized as RFC 3339
+    pub created_at: Option<DateTime<Utc>>,
+
+    /// Timestamp when the user was last updated, serialized as RFC 3339
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// Request body for `POST /users`
+///
+/// # Example JSON Input
+/// ```json
+/// {
+///   "username": "jane_doe",
+///   "email": "jane@example.com",
+///   "full_name": "Jane Doe",
+///   "password": "correct-horse-battery-staple"
+/// }
+/// ```
+#[derive(Deserialize, ToSchema)]
+pub struct CreateUser {
+    /// Unique username for authentication
+    pub username: String,
+
+    /// User's email address
+    pub email: String,
+
+    /// User's full display name
+    pub full_name: String,
+
+    /// Plaintext password; hashed with bcrypt before it touches the database
+    pub password: String,
+}
+
+/// Request body for `PUT /users/{id}`
+///
+/// All fields are required; this endpoint replaces the row rather than
+/// patching individual columns.
+#[derive(Deserialize, ToSchema)]
+pub struct UpdateUser {
+    /// Unique username for authentication
+    pub username: String,
+
+    /// User's email address
+    pub email: String,
+
+    /// User's full display name
+    pub full_name: String,
+}
+
+/// Response body for `GET /health/ready`
+#[derive(Serialize, ToSchema)]
+pub struct HealthStatus {
+    /// `"ok"` on success, `"error"` on failure
+    pub status: String,
+
+    /// `"up"` if the readiness probe reached the database, `"down"` otherwise
+    pub database: String,
+}
+
+/// JWT claims issued by `POST /auth/login` and validated on every protected route
+#[derive(Serialize, Deserialize)]
+pub struct Claims {
+    /// Subject: the authenticated user's id, as a string
+    pub sub: String,
+
+    /// Issued-at time, Unix seconds
+    pub iat: usize,
+
+    /// Expiration time, Unix seconds
+    pub exp: usize,
 }
@@ -0,0 +1,150 @@
+//! End-to-end tests that boot the real server on an ephemeral port and drive
+//! it over HTTP with `reqwest`, the way a real client would.
+
+mod helpers;
+
+use helpers::spawn_app;
+use serde_json::{json, Value};
+
+#[actix_web::test]
+async fn health_check_returns_ok() {
+    let app = spawn_app().await;
+    let client = reqwest::Client::new();
+
+    let response = client
+        .get(app.url("/health"))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(200, response.status().as_u16());
+}
+
+#[actix_web::test]
+async fn health_ready_reports_database_up() {
+    let app = spawn_app().await;
+    let client = reqwest::Client::new();
+
+    let response = client
+        .get(app.url("/health/ready"))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(200, response.status().as_u16());
+    let body: Value = response.json().await.expect("Response was not valid JSON");
+    assert_eq!(body["database"], "up");
+}
+
+#[actix_web::test]
+async fn get_users_requires_a_bearer_token() {
+    let app = spawn_app().await;
+    let client = reqwest::Client::new();
+
+    let response = client
+        .get(app.url("/users"))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(401, response.status().as_u16());
+}
+
+#[actix_web::test]
+async fn login_rejects_unknown_username() {
+    let app = spawn_app().await;
+    let client = reqwest::Client::new();
+
+    let response = client
+        .post(app.url("/auth/login"))
+        .json(&json!({ "username": app.unique("nobody"), "password": "wrong" }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(401, response.status().as_u16());
+}
+
+#[actix_web::test]
+async fn crud_flow_creates_reads_updates_and_deletes_a_user() {
+    let app = spawn_app().await;
+    let client = reqwest::Client::new();
+
+    let username = app.unique("jane_doe");
+    let email = format!("{}@example.com", username);
+    app.seed_user(&username, &email, "correct-password").await;
+
+    // Log in as the seeded user to get a bearer token
+    let login_response = client
+        .post(app.url("/auth/login"))
+        .json(&json!({ "username": username, "password": "correct-password" }))
+        .send()
+        .await
+        .expect("Failed to execute login request");
+    assert_eq!(200, login_response.status().as_u16());
+    let login_body: Value = login_response.json().await.expect("Invalid login JSON");
+    let token = login_body["token"].as_str().expect("Missing token").to_string();
+
+    // Create a second user through the protected CRUD endpoint
+    let created_username = app.unique("john_doe");
+    let create_response = client
+        .post(app.url("/users"))
+        .bearer_auth(&token)
+        .json(&json!({
+            "username": created_username,
+            "email": format!("{}@example.com", created_username),
+            "full_name": "John Doe",
+            "password": "another-correct-password",
+        }))
+        .send()
+        .await
+        .expect("Failed to execute create request");
+    assert_eq!(201, create_response.status().as_u16());
+    let created: Value = create_response.json().await.expect("Invalid create JSON");
+    let id = created["id"].as_i64().expect("Missing id");
+
+    // Read it back
+    let get_response = client
+        .get(app.url(&format!("/users/{}", id)))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .expect("Failed to execute get request");
+    assert_eq!(200, get_response.status().as_u16());
+
+    // Update it
+    let update_response = client
+        .put(app.url(&format!("/users/{}", id)))
+        .bearer_auth(&token)
+        .json(&json!({
+            "username": created_username,
+            "email": format!("{}@example.com", created_username),
+            "full_name": "Johnny Doe",
+        }))
+        .send()
+        .await
+        .expect("Failed to execute update request");
+    assert_eq!(200, update_response.status().as_u16());
+    let updated: Value = update_response.json().await.expect("Invalid update JSON");
+    assert_eq!(updated["full_name"], "Johnny Doe");
+
+    // Delete it
+    let delete_response = client
+        .delete(app.url(&format!("/users/{}", id)))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .expect("Failed to execute delete request");
+    assert_eq!(204, delete_response.status().as_u16());
+
+    // A second delete finds nothing left to remove
+    let second_delete = client
+        .delete(app.url(&format!("/users/{}", id)))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .expect("Failed to execute second delete request");
+    assert_eq!(404, second_delete.status().as_u16());
+
+    app.cleanup().await;
+}
@@ -0,0 +1,102 @@
+//! Shared setup for the integration tests: boots the app on an OS-assigned
+//! port against the same database the tests talk to directly, mirroring the
+//! spawn-app pattern used by other actix-web services.
+
+use std::net::TcpListener;
+
+use rust_rest_apis::{auth, db};
+use tiberius::Query;
+
+/// A running instance of the app, plus enough state for a test to seed its
+/// own rows and clean them up afterwards.
+pub struct TestApp {
+    pub address: String,
+    pub pool: db::DbClient,
+    /// Unique per spawned app so every seeded username/email can't collide
+    /// with another test running concurrently against the same `users` table.
+    pub suffix: String,
+}
+
+impl TestApp {
+    /// Build a full URL for `path` against this instance, e.g. `/users`
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{}", self.address, path)
+    }
+
+    /// A username/email unique to this test run, e.g. `jane_doe_3f9a2b1c4d5e6f708192a3b4c5d6e7f8`
+    pub fn unique(&self, prefix: &str) -> String {
+        format!("{}_{}", prefix, self.suffix)
+    }
+
+    /// Insert a user directly (bypassing `POST /users`, which itself requires a
+    /// bearer token) so tests can exercise `POST /auth/login`.
+    pub async fn seed_user(&self, username: &str, email: &str, password: &str) -> i32 {
+        let password_hash =
+            bcrypt::hash(password, bcrypt::DEFAULT_COST).expect("Failed to hash test password");
+
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .expect("Failed to get DB connection");
+
+        let mut query = Query::new(
+            "INSERT INTO users (username, email, full_name, password_hash) \
+             VALUES (@P1, @P2, @P3, @P4); SELECT CAST(SCOPE_IDENTITY() AS INT) AS id",
+        );
+        query.bind(username.to_string());
+        query.bind(email.to_string());
+        query.bind(format!("{} Test", username));
+        query.bind(password_hash);
+
+        let row = query
+            .query(&mut *conn)
+            .await
+            .expect("Failed to seed test user")
+            .into_row()
+            .await
+            .expect("Failed to read seeded user id")
+            .expect("Insert did not return a generated id");
+
+        row.get::<i32, _>("id").unwrap_or(0)
+    }
+
+    /// Delete every row this test seeded, identified by its unique suffix
+    pub async fn cleanup(&self) {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .expect("Failed to get DB connection");
+
+        let mut query = Query::new("DELETE FROM users WHERE username LIKE @P1");
+        query.bind(format!("%{}", self.suffix));
+        let _ = query.query(&mut *conn).await;
+    }
+}
+
+/// Boot the app on a free OS-assigned port
+///
+/// Reads the same `DB_*` / `JWT_*` environment variables as production, so
+/// point the test environment at a disposable test database before running
+/// this suite.
+pub async fn spawn_app() -> TestApp {
+    let pool = db::init_db()
+        .await
+        .expect("Failed to connect to the test database");
+
+    let config = auth::Config::init();
+
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind random port");
+    let address = format!("http://{}", listener.local_addr().unwrap());
+
+    let server = rust_rest_apis::run(listener, pool.clone(), config)
+        .expect("Failed to bind address");
+    tokio::spawn(server);
+
+    TestApp {
+        address,
+        pool,
+        suffix: uuid::Uuid::new_v4().simple().to_string(),
+    }
+}